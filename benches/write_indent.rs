@@ -0,0 +1,28 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use indt::{Indent, IndentConfig};
+use std::io::Write;
+
+const LEVELS: u8 = 32;
+const LINES: usize = 10_000;
+
+fn deeply_indented_output(c: &mut Criterion) {
+    c.bench_function("write deeply indented lines", |b| {
+        b.iter(|| {
+            let mut buffer = Vec::new();
+            let mut indent = Indent::new(&mut buffer, IndentConfig::Space(4));
+
+            for _ in 0..LEVELS {
+                indent.more();
+            }
+
+            for _ in 0..LINES {
+                writeln!(indent, "line").unwrap();
+            }
+
+            buffer
+        })
+    });
+}
+
+criterion_group!(benches, deeply_indented_output);
+criterion_main!(benches);
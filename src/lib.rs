@@ -8,14 +8,261 @@
 //! [indenter]: https://crates.io/crates/indenter/
 
 use std::cmp;
+use std::fmt;
 use std::io;
 
+/// Describes what a single level of indentation looks like.
+///
+/// An `IndentConfig` is resolved into bytes once per level change (see `more`/`less`),
+/// so a level always corresponds to one atomic unit, not a fixed number of characters.
+/// This matters for `Tab`, where one level is one `\t` regardless of how wide a tab
+/// renders.
+///
+/// # Examples
+///
+/// ```
+/// use indt::{Indent, IndentConfig};
+/// use std::io::Write;
+///
+/// let mut buffer = Vec::new();
+/// let mut indent = Indent::new(&mut buffer, IndentConfig::Tab);
+///
+/// indent.more();
+///
+/// write!(indent, "lorem ipsum");
+///
+/// assert_eq!("\tlorem ipsum", String::from_utf8_lossy(&buffer));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IndentConfig {
+    /// Indent with `n` spaces per level.
+    Space(usize),
+    /// Indent with a single tab character per level.
+    Tab,
+    /// Indent with an arbitrary string per level.
+    Custom(String),
+    /// Draw ASCII-art tree guides instead of plain whitespace, see `TreeStyle`.
+    Tree(TreeStyle),
+}
+
+impl IndentConfig {
+    fn push_unit(&self, buffer: &mut Vec<u8>) {
+        match self {
+            IndentConfig::Space(n) => buffer.extend(std::iter::repeat_n(b' ', *n)),
+            IndentConfig::Tab => buffer.push(b'\t'),
+            IndentConfig::Custom(s) => buffer.extend_from_slice(s.as_bytes()),
+            IndentConfig::Tree(_) => {}
+        }
+    }
+
+    fn unit_len(&self) -> usize {
+        match self {
+            IndentConfig::Space(n) => *n,
+            IndentConfig::Tab => 1,
+            IndentConfig::Custom(s) => s.len(),
+            IndentConfig::Tree(_) => 0,
+        }
+    }
+}
+
+/// Glyphs used to draw tree guides for `IndentConfig::Tree`.
+///
+/// Each active indent level contributes one guide column. The deepest level
+/// (the one currently being written to) gets a branch glyph - `branch` for a
+/// regular child, `last_branch` for the final child of its parent, followed
+/// by `horizontal` - while ancestor levels show `vertical` (or blank, once
+/// they've been marked as a last child via `more_last`).
+///
+/// `wraparound`, if greater than `0`, bounds the drawn width by only showing
+/// guides for the `wraparound` innermost levels, so very deep trees don't
+/// produce unboundedly wide prefixes. `0` disables wraparound.
+///
+/// # Examples
+///
+/// ```
+/// use indt::{Indent, IndentConfig, TreeStyle};
+/// use std::io::Write;
+///
+/// let mut buffer = Vec::new();
+/// let mut indent = Indent::new(&mut buffer, IndentConfig::Tree(TreeStyle::default()));
+///
+/// writeln!(indent, "root").unwrap();
+///
+/// indent.more();
+/// writeln!(indent, "child 1").unwrap();
+///
+/// indent.less().more_last();
+/// write!(indent, "child 2").unwrap();
+///
+/// assert_eq!(
+///     "root\n├─child 1\n└─child 2",
+///     String::from_utf8_lossy(&buffer)
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TreeStyle {
+    pub vertical: char,
+    pub branch: char,
+    pub last_branch: char,
+    pub horizontal: char,
+    pub wraparound: usize,
+}
+
+impl Default for TreeStyle {
+    fn default() -> Self {
+        TreeStyle {
+            vertical: '│',
+            branch: '├',
+            last_branch: '└',
+            horizontal: '─',
+            wraparound: 0,
+        }
+    }
+}
+
+/// Shared level/buffer bookkeeping behind both `Indent` (an `io::Write` sink)
+/// and `IndentFmt` (a `fmt::Write` sink). It never writes to the underlying
+/// sink itself - that differs by trait - but holds the depth tracking, prefix
+/// caching and dedent stripping both use. Each writer splits its input on
+/// newlines in its own `write`/`write_str`.
+struct IndentState {
+    config: IndentConfig,
+    indent_buffer: Vec<u8>,
+    tree_buffer: Vec<u8>,
+    current_level: u8,
+    is_last: Vec<bool>,
+    first_line: bool,
+    dedent: bool,
+    common_indent: Option<usize>,
+}
+
+impl IndentState {
+    fn new(config: IndentConfig) -> IndentState {
+        IndentState {
+            config,
+            indent_buffer: Vec::new(),
+            tree_buffer: Vec::new(),
+            current_level: 0,
+            is_last: Vec::new(),
+            first_line: true,
+            dedent: false,
+            common_indent: None,
+        }
+    }
+
+    fn enable_dedent(&mut self) {
+        self.dedent = true;
+    }
+
+    fn strip_common_indent<'b>(&mut self, line: &'b [u8]) -> &'b [u8] {
+        if !self.dedent || line.is_empty() {
+            return line;
+        }
+
+        let leading = line
+            .iter()
+            .take_while(|b| **b == b' ' || **b == b'\t')
+            .count();
+
+        if leading == line.len() && self.common_indent.is_none() {
+            return line;
+        }
+
+        let common = *self.common_indent.get_or_insert(leading);
+        let stripped = cmp::min(common, leading);
+
+        &line[stripped..]
+    }
+
+    fn push_level(&mut self, is_last: bool) {
+        if self.current_level < u8::MAX {
+            self.current_level += 1;
+            self.is_last.push(is_last);
+            self.config.push_unit(&mut self.indent_buffer);
+            self.rebuild_tree_buffer();
+        }
+    }
+
+    fn pop_level(&mut self) {
+        if self.current_level > 0 {
+            self.current_level -= 1;
+            self.is_last.pop();
+            let new_len = self.indent_buffer.len() - self.config.unit_len();
+            self.indent_buffer.truncate(new_len);
+            self.rebuild_tree_buffer();
+        }
+    }
+
+    fn set_level(&mut self, level: u8) {
+        self.current_level = level;
+        self.is_last = vec![false; level as usize];
+
+        self.indent_buffer.clear();
+        for _ in 0..self.current_level {
+            self.config.push_unit(&mut self.indent_buffer);
+        }
+        self.rebuild_tree_buffer();
+    }
+
+    /// Rebuilds the cached tree guide prefix from `is_last`. A no-op for
+    /// non-`Tree` configs, which instead maintain `indent_buffer` directly in
+    /// `push_level`/`pop_level`/`set_level`. Called only on depth changes,
+    /// not per line, so `prefix` stays a precomputed buffer even for
+    /// deeply-indented, many-line output.
+    fn rebuild_tree_buffer(&mut self) {
+        let style = match &self.config {
+            IndentConfig::Tree(style) => style,
+            _ => return,
+        };
+
+        let total = self.is_last.len();
+        let start = if style.wraparound > 0 && total > style.wraparound {
+            total - style.wraparound
+        } else {
+            0
+        };
+
+        self.tree_buffer.clear();
+        let mut guide = String::new();
+        for (i, is_last) in self.is_last[start..].iter().enumerate() {
+            if start + i == total - 1 {
+                guide.push(if *is_last {
+                    style.last_branch
+                } else {
+                    style.branch
+                });
+                guide.push(style.horizontal);
+            } else if *is_last {
+                guide.push(' ');
+                guide.push(' ');
+            } else {
+                guide.push(style.vertical);
+                guide.push(' ');
+            }
+        }
+        self.tree_buffer.extend_from_slice(guide.as_bytes());
+    }
+
+    /// The current indent prefix. Always valid UTF-8: `Space`/`Tab` produce
+    /// ASCII, `Custom` is built from a `String`, and `Tree` guide glyphs are
+    /// pushed through a `String` as well.
+    fn prefix(&self) -> &[u8] {
+        match &self.config {
+            IndentConfig::Tree(_) => &self.tree_buffer,
+            _ => &self.indent_buffer,
+        }
+    }
+}
+
 /// Represent struct used for printing with indentions.
 ///
 /// Create it with indention style options with `new` method or
 /// with default styles via `from_writer` method.
 /// Use `more` and `less` methods to specify indention depth.
 ///
+/// For `fmt::Write` sinks (e.g. a `fmt::Formatter` inside a `Display`/`Debug`
+/// impl), see `IndentFmt` instead.
+///
 /// # Examples
 ///
 /// ```
@@ -33,15 +280,12 @@ use std::io;
 /// ```
 pub struct Indent<'a> {
     output: &'a mut dyn io::Write,
-    indent_step: u8,
-    indent_symbol: char,
-    current_indent: u8,
-    first_line: bool,
+    state: IndentState,
 }
 
 impl<'a> Indent<'a> {
     /// Creates a new instance of the `Indent` struct with default indent options.
-    /// Default indent character is whitespace `' '` and default indent is 4 characters long.
+    /// Default indent is `IndentConfig::Space(4)`, i.e. 4 spaces per level.
     ///
     /// ## Arguments
     ///
@@ -64,7 +308,7 @@ impl<'a> Indent<'a> {
     ///
     /// ```
     pub fn from_writer(output: &'a mut dyn io::Write) -> Indent<'a> {
-        Self::new(output, 4, ' ')
+        Self::new(output, IndentConfig::Space(4))
     }
 
     /// Creates a new instance of the `Indent` struct with specified indent options.
@@ -72,17 +316,16 @@ impl<'a> Indent<'a> {
     /// ## Arguments
     ///
     /// * `output` - Writing destination
-    /// * `indent_step` - Size of one indent in characters
-    /// * `indent_symbol` - Character that will be used to write indent
+    /// * `config` - Style of a single indentation level
     ///
     /// # Examples
     ///
     /// ```
-    /// use indt::Indent;
+    /// use indt::{Indent, IndentConfig};
     /// use std::io::Write;
     ///
     /// let mut buffer = Vec::new();
-    /// let mut indent = Indent::new(&mut buffer, 3, '-');
+    /// let mut indent = Indent::new(&mut buffer, IndentConfig::Custom(String::from("---")));
     ///
     /// indent.more();
     ///
@@ -91,101 +334,208 @@ impl<'a> Indent<'a> {
     /// assert_eq!("---lorem ipsum", String::from_utf8_lossy(&buffer));
     ///
     /// ```
-    pub fn new(output: &'a mut dyn io::Write, indent_step: u8, indent_symbol: char) -> Indent<'a> {
+    pub fn new(output: &'a mut dyn io::Write, config: IndentConfig) -> Indent<'a> {
         Indent {
             output,
-            indent_step,
-            indent_symbol,
-            current_indent: 0,
-            first_line: true,
+            state: IndentState::new(config),
         }
     }
 
-    /// Increases indent by `indent_step` specified in `new` method.
+    /// Enables dedent mode: re-anchors pre-formatted input to the current
+    /// indent level instead of keeping the leading whitespace it arrives with.
+    ///
+    /// On the first nonblank line written, the number of leading whitespace
+    /// characters is recorded as the "common" prefix. Every following line has
+    /// up to that many leading whitespace characters stripped before the
+    /// current indent is applied, so the block's own relative nesting is kept
+    /// while the block as a whole is re-anchored. Empty lines are emitted
+    /// empty; a line holding only whitespace is written as the current indent
+    /// followed by whatever whitespace is left after stripping. This is useful
+    /// for feeding in pre-formatted source, e.g. an
+    /// `include_str!` code block, that carries its own indentation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use indt::Indent;
+    /// use std::io::Write;
+    ///
+    /// let mut buffer = Vec::new();
+    /// let mut indent = Indent::from_writer(&mut buffer);
+    ///
+    /// indent.more().dedent();
+    ///
+    /// write!(indent, "  fn main() {{\n      println!();\n  }}").unwrap();
+    ///
+    /// assert_eq!(
+    ///     "    fn main() {\n        println!();\n    }",
+    ///     String::from_utf8_lossy(&buffer)
+    /// );
+    /// ```
+    pub fn dedent(&mut self) -> &mut Indent<'a> {
+        self.state.enable_dedent();
+
+        self
+    }
+
+    /// Increases indent by one level.
     /// Maximum value is `u8::MAX`.
     /// Initial indent set to `0`.
     ///
     /// # Examples
     ///
     /// ```
-    /// use indt::Indent;
+    /// use indt::{Indent, IndentConfig};
     /// use std::io::Write;
     ///
     /// let mut buffer = Vec::new();
-    /// let mut indent = Indent::new(&mut buffer, 2, '.');
+    /// let mut indent = Indent::new(&mut buffer, IndentConfig::Space(2));
     ///
     /// indent.more().more().more();
     ///
     /// write!(indent, "lorem ipsum");
     ///
-    /// assert_eq!("......lorem ipsum", String::from_utf8_lossy(&buffer));
+    /// assert_eq!("      lorem ipsum", String::from_utf8_lossy(&buffer));
     ///
     /// ```
     pub fn more(&mut self) -> &mut Indent<'a> {
-        let next_indent = self.current_indent as u16 + self.indent_step as u16;
-        self.current_indent = cmp::min(next_indent, u8::MAX as u16) as u8;
+        self.state.push_level(false);
 
         self
     }
 
-    /// Decreases indent by `indent_step` specified in `new` method.
+    /// Increases indent by one level, marking it as the last child of its
+    /// parent. Only meaningful with `IndentConfig::Tree`, where it selects
+    /// the closing `last_branch` guide glyph instead of `branch`; for other
+    /// configs it behaves exactly like `more`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use indt::{Indent, IndentConfig, TreeStyle};
+    /// use std::io::Write;
+    ///
+    /// let mut buffer = Vec::new();
+    /// let mut indent = Indent::new(&mut buffer, IndentConfig::Tree(TreeStyle::default()));
+    ///
+    /// indent.more_last();
+    ///
+    /// write!(indent, "last child");
+    ///
+    /// assert_eq!("└─last child", String::from_utf8_lossy(&buffer));
+    /// ```
+    pub fn more_last(&mut self) -> &mut Indent<'a> {
+        self.state.push_level(true);
+
+        self
+    }
+
+    /// Decreases indent by one level.
     /// Minimum value is `u8::MIN`
     /// Initial indent set to `0`.
     ///
     /// # Examples
     ///
     /// ```
-    /// use indt::Indent;
+    /// use indt::{Indent, IndentConfig};
     /// use std::io::Write;
     ///
     /// let mut buffer = Vec::new();
-    /// let mut indent = Indent::new(&mut buffer, 2, '.');
+    /// let mut indent = Indent::new(&mut buffer, IndentConfig::Space(2));
     ///
     /// indent.more().more().less();
     ///
     /// write!(indent, "lorem ipsum");
     ///
-    /// assert_eq!("..lorem ipsum", String::from_utf8_lossy(&buffer));
+    /// assert_eq!("  lorem ipsum", String::from_utf8_lossy(&buffer));
     ///
     /// ```
     pub fn less(&mut self) -> &mut Indent<'a> {
-        let next_indent = self.current_indent as i16 - self.indent_step as i16;
-        self.current_indent = cmp::max(next_indent, u8::MIN as i16) as u8;
+        self.state.pop_level();
 
         self
     }
 
-    /// Writes indent with `indent_symbol` and `current_indent` length long to `output`.
-    fn write_indent(&mut self) -> Result<(), io::Error> {
-        for _ in 0..self.current_indent {
-            write!(self.output, "{}", self.indent_symbol)?;
-        }
+    /// Sets indent to an absolute `level`, regardless of the current depth.
+    /// Saturates at `u8::MAX`.
+    ///
+    /// Useful when the target depth is already known, e.g. when walking an
+    /// error `source()` chain and writing each cause at level `i`, instead of
+    /// tracking balanced `more`/`less` calls yourself.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use indt::{Indent, IndentConfig};
+    /// use std::io::Write;
+    ///
+    /// let mut buffer = Vec::new();
+    /// let mut indent = Indent::new(&mut buffer, IndentConfig::Space(2));
+    ///
+    /// indent.ind(3);
+    ///
+    /// write!(indent, "lorem ipsum");
+    ///
+    /// assert_eq!("      lorem ipsum", String::from_utf8_lossy(&buffer));
+    /// ```
+    pub fn ind(&mut self, level: u8) -> &mut Indent<'a> {
+        self.state.set_level(level);
 
-        Ok(())
+        self
+    }
+
+    /// Returns the current indent level, i.e. the number of levels added by
+    /// `more()`/`more_last()` and not yet removed by `less()`, at most
+    /// `u8::MAX` (or the level last set via `ind`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use indt::Indent;
+    ///
+    /// let mut buffer = Vec::new();
+    /// let mut indent = Indent::from_writer(&mut buffer);
+    ///
+    /// indent.more().more();
+    ///
+    /// assert_eq!(2, indent.level());
+    /// ```
+    pub fn level(&self) -> u8 {
+        self.state.current_level
+    }
+
+    /// Writes the current indent to `output` in a single `write_all` call.
+    fn write_indent(&mut self) -> Result<(), io::Error> {
+        self.output.write_all(self.state.prefix())
     }
 }
 
 impl<'a> io::Write for Indent<'a> {
     fn write(&mut self, s: &[u8]) -> Result<usize, io::Error> {
-        if self.first_line {
+        if self.state.first_line {
             self.write_indent()?;
-            self.first_line = false;
+            self.state.first_line = false;
         }
 
         let mut splitted = s.split(|x| *x == b'\n');
         let mut printed: usize = 0;
 
         if let Some(first) = splitted.next() {
-            printed += self.output.write(first)?;
+            let stripped = self.state.strip_common_indent(first);
+            self.output.write_all(stripped)?;
+            printed += first.len();
 
             for line in splitted {
-                printed += self.output.write(b"\n")?;
+                self.output.write_all(b"\n")?;
+                printed += 1;
 
                 if !line.is_empty() {
                     self.write_indent()?;
-                    printed += self.output.write(line)?;
+                    let stripped = self.state.strip_common_indent(line);
+                    self.output.write_all(stripped)?;
+                    printed += line.len();
                 } else {
-                    self.first_line = true;
+                    self.state.first_line = true;
                 }
             }
         }
@@ -198,6 +548,134 @@ impl<'a> io::Write for Indent<'a> {
     }
 }
 
+/// Same indentation behaviour as `Indent`, but for `fmt::Write` sinks rather
+/// than `io::Write` ones - most commonly a `&mut fmt::Formatter` inside a
+/// `Display`/`Debug` implementation, where an `io::Write` sink isn't available.
+///
+/// # Examples
+///
+/// ```
+/// use indt::IndentFmt;
+/// use std::fmt;
+/// use std::fmt::Write as _;
+///
+/// struct Tree;
+///
+/// impl fmt::Display for Tree {
+///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+///         let mut indent = IndentFmt::from_writer(f);
+///
+///         indent.more();
+///
+///         write!(indent, "lorem ipsum")
+///     }
+/// }
+///
+/// assert_eq!("    lorem ipsum", Tree.to_string());
+/// ```
+pub struct IndentFmt<'a> {
+    output: &'a mut dyn fmt::Write,
+    state: IndentState,
+}
+
+impl<'a> IndentFmt<'a> {
+    /// See `Indent::from_writer`.
+    pub fn from_writer(output: &'a mut dyn fmt::Write) -> IndentFmt<'a> {
+        Self::new(output, IndentConfig::Space(4))
+    }
+
+    /// See `Indent::new`.
+    pub fn new(output: &'a mut dyn fmt::Write, config: IndentConfig) -> IndentFmt<'a> {
+        IndentFmt {
+            output,
+            state: IndentState::new(config),
+        }
+    }
+
+    /// See `Indent::dedent`.
+    pub fn dedent(&mut self) -> &mut IndentFmt<'a> {
+        self.state.enable_dedent();
+
+        self
+    }
+
+    /// See `Indent::more`.
+    pub fn more(&mut self) -> &mut IndentFmt<'a> {
+        self.state.push_level(false);
+
+        self
+    }
+
+    /// See `Indent::more_last`.
+    pub fn more_last(&mut self) -> &mut IndentFmt<'a> {
+        self.state.push_level(true);
+
+        self
+    }
+
+    /// See `Indent::less`.
+    pub fn less(&mut self) -> &mut IndentFmt<'a> {
+        self.state.pop_level();
+
+        self
+    }
+
+    /// See `Indent::ind`.
+    pub fn ind(&mut self, level: u8) -> &mut IndentFmt<'a> {
+        self.state.set_level(level);
+
+        self
+    }
+
+    /// See `Indent::level`.
+    pub fn level(&self) -> u8 {
+        self.state.current_level
+    }
+
+    /// Writes the current indent to `output` in a single `write_str` call.
+    fn write_indent(&mut self) -> fmt::Result {
+        let prefix = prefix_as_str(self.state.prefix());
+        self.output.write_str(prefix)
+    }
+}
+
+impl<'a> fmt::Write for IndentFmt<'a> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if self.state.first_line {
+            self.write_indent()?;
+            self.state.first_line = false;
+        }
+
+        let mut splitted = s.as_bytes().split(|x| *x == b'\n');
+
+        if let Some(first) = splitted.next() {
+            let stripped = self.state.strip_common_indent(first);
+            self.output.write_str(prefix_as_str(stripped))?;
+
+            for line in splitted {
+                self.output.write_str("\n")?;
+
+                if !line.is_empty() {
+                    self.write_indent()?;
+                    let stripped = self.state.strip_common_indent(line);
+                    self.output.write_str(prefix_as_str(stripped))?;
+                } else {
+                    self.state.first_line = true;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Every byte slice handed to `write_str` here is either an indent prefix
+/// (valid UTF-8 for every config, see `IndentState::prefix`) or a substring of the `&str` passed into `write_str`, split
+/// on the single-byte `'\n'` - both are guaranteed valid UTF-8.
+fn prefix_as_str(bytes: &[u8]) -> &str {
+    std::str::from_utf8(bytes).expect("indent prefix and split lines are always valid UTF-8")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -290,7 +768,7 @@ mod tests {
         writeln!(indt, "first line").unwrap();
 
         assert_eq!(
-            "                                                                                                                                                                                                                                                               first line\n", 
+            format!("{}first line\n", " ".repeat(255 * 4)),
             String::from_utf8_lossy(&buffer)
         );
     }
@@ -298,7 +776,7 @@ mod tests {
     #[test]
     pub fn custom_indent() {
         let mut buffer = Vec::new();
-        let mut indt = Indent::new(&mut buffer, 2, '.');
+        let mut indt = Indent::new(&mut buffer, IndentConfig::Custom(String::from("..")));
 
         indt.more();
 
@@ -328,4 +806,167 @@ mod tests {
             String::from_utf8_lossy(&buffer)
         );
     }
+
+    #[test]
+    pub fn ind_sets_absolute_level() {
+        let mut buffer = Vec::new();
+        let mut indt = Indent::from_writer(&mut buffer);
+
+        indt.more().more().more();
+        indt.ind(1);
+
+        write!(indt, "first line").unwrap();
+        let level = indt.level();
+
+        assert_eq!("    first line", String::from_utf8_lossy(&buffer));
+        assert_eq!(1, level);
+    }
+
+    #[test]
+    pub fn level_tracks_more_and_less() {
+        let mut buffer = Vec::new();
+        let mut indt = Indent::from_writer(&mut buffer);
+
+        assert_eq!(0, indt.level());
+
+        indt.more().more();
+        assert_eq!(2, indt.level());
+
+        indt.less();
+        assert_eq!(1, indt.level());
+    }
+
+    #[test]
+    pub fn dedent_reanchors_block_to_current_indent() {
+        let mut buffer = Vec::new();
+        let mut indt = Indent::from_writer(&mut buffer);
+
+        indt.more().dedent();
+
+        write!(indt, "  fn main() {{\n      println!();\n  }}").unwrap();
+
+        assert_eq!(
+            "    fn main() {\n        println!();\n    }",
+            String::from_utf8_lossy(&buffer)
+        );
+    }
+
+    #[test]
+    pub fn dedent_emits_blank_lines_empty() {
+        let mut buffer = Vec::new();
+        let mut indt = Indent::from_writer(&mut buffer);
+
+        indt.more().dedent();
+
+        write!(indt, "  first\n\n  second").unwrap();
+
+        assert_eq!("    first\n\n    second", String::from_utf8_lossy(&buffer));
+    }
+
+    #[test]
+    pub fn tree_guides_for_nested_children() {
+        let mut buffer = Vec::new();
+        let mut indt = Indent::new(&mut buffer, IndentConfig::Tree(TreeStyle::default()));
+
+        writeln!(indt, "root").unwrap();
+
+        indt.more();
+        writeln!(indt, "child 1").unwrap();
+
+        indt.more_last();
+        writeln!(indt, "grandchild").unwrap();
+        indt.less();
+
+        indt.less().more_last();
+        write!(indt, "child 2").unwrap();
+
+        assert_eq!(
+            "root\n├─child 1\n│ └─grandchild\n└─child 2",
+            String::from_utf8_lossy(&buffer)
+        );
+    }
+
+    #[test]
+    pub fn tree_guides_wraparound_bounds_width() {
+        let mut buffer = Vec::new();
+        let style = TreeStyle {
+            wraparound: 2,
+            ..TreeStyle::default()
+        };
+        let mut indt = Indent::new(&mut buffer, IndentConfig::Tree(style));
+
+        indt.more().more().more();
+        write!(indt, "deep").unwrap();
+
+        assert_eq!("│ ├─deep", String::from_utf8_lossy(&buffer));
+    }
+
+    #[test]
+    pub fn tree_guide_buffer_is_rebuilt_on_depth_change() {
+        let mut buffer = Vec::new();
+        let mut indt = Indent::new(&mut buffer, IndentConfig::Tree(TreeStyle::default()));
+
+        indt.more();
+        writeln!(indt, "a").unwrap();
+
+        indt.less().more_last();
+        write!(indt, "b").unwrap();
+
+        assert_eq!("├─a\n└─b", String::from_utf8_lossy(&buffer));
+    }
+
+    #[test]
+    pub fn tab_indent() {
+        let mut buffer = Vec::new();
+        let mut indt = Indent::new(&mut buffer, IndentConfig::Tab);
+
+        indt.more().more();
+
+        write!(indt, "first line").unwrap();
+
+        assert_eq!("\t\tfirst line", String::from_utf8_lossy(&buffer));
+    }
+
+    #[test]
+    pub fn fmt_first_line_with_one_indent() {
+        let mut buffer = String::new();
+        let mut indt = IndentFmt::from_writer(&mut buffer);
+
+        indt.more();
+        fmt::Write::write_str(&mut indt, "first line").unwrap();
+
+        assert_eq!("    first line", buffer);
+    }
+
+    #[test]
+    pub fn fmt_multiple_lines() {
+        use std::fmt::Write as _;
+
+        let mut buffer = String::new();
+        let mut indt = IndentFmt::new(&mut buffer, IndentConfig::Custom(String::from("..")));
+
+        indt.more();
+
+        write!(indt, "first line\nsecond line").unwrap();
+
+        assert_eq!("..first line\n..second line", buffer);
+    }
+
+    #[test]
+    pub fn fmt_write_inside_display_impl() {
+        struct Nested;
+
+        impl fmt::Display for Nested {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let mut indent = IndentFmt::from_writer(f);
+
+                indent.more();
+                fmt::Write::write_str(&mut indent, "child 1\n")?;
+                indent.less().more();
+                fmt::Write::write_str(&mut indent, "child 2")
+            }
+        }
+
+        assert_eq!("    child 1\n    child 2", Nested.to_string());
+    }
 }